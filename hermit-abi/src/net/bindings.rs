@@ -2,6 +2,7 @@ use crate::io::Result;
 use crate::net::event::{Event, EventFlags};
 use crate::net::{Shutdown, Socket, SocketAddr};
 use core::mem::MaybeUninit;
+use core::net::{Ipv4Addr, Ipv6Addr};
 use core::time::Duration;
 
 extern "Rust" {
@@ -37,6 +38,18 @@ extern "Rust" {
 	fn sys_tcp_write(socket: Socket, buf: &[u8]) -> Result<usize>;
 	fn sys_tcp_read(socket: Socket, buf: &mut [u8]) -> Result<usize>;
 	fn sys_tcp_peek(socket: Socket, buf: &mut [u8]) -> Result<usize>;
+
+	// UDP
+	fn sys_udp_bind(socket: Socket, local: SocketAddr) -> Result<()>;
+	fn sys_udp_connect(socket: Socket, remote: SocketAddr) -> Result<()>;
+	fn sys_udp_send_to(socket: Socket, buf: &[u8], remote: SocketAddr) -> Result<usize>;
+	fn sys_udp_recv_from(socket: Socket, buf: &mut [u8]) -> Result<(usize, SocketAddr)>;
+	fn sys_udp_set_broadcast(socket: Socket, broadcast: bool) -> Result<()>;
+	fn sys_udp_broadcast(socket: Socket) -> Result<bool>;
+	fn sys_udp_set_multicast_loop(socket: Socket, loop_back: bool) -> Result<()>;
+	fn sys_udp_multicast_loop(socket: Socket) -> Result<bool>;
+	fn sys_udp_join_multicast_v4(socket: Socket, multiaddr: Ipv4Addr, interface: Ipv4Addr) -> Result<()>;
+	fn sys_udp_join_multicast_v6(socket: Socket, multiaddr: Ipv6Addr, interface: u32) -> Result<()>;
 }
 
 // socket
@@ -145,3 +158,45 @@ pub fn tcp_read(socket: Socket, buf: &mut [u8]) -> Result<usize> {
 pub fn tcp_peek(socket: Socket, buf: &mut [u8]) -> Result<usize> {
 	unsafe { sys_tcp_peek(socket, buf) }
 }
+
+// udp
+
+pub fn udp_bind(socket: Socket, local: SocketAddr) -> Result<()> {
+	unsafe { sys_udp_bind(socket, local) }
+}
+
+pub fn udp_connect(socket: Socket, remote: SocketAddr) -> Result<()> {
+	unsafe { sys_udp_connect(socket, remote) }
+}
+
+pub fn udp_send_to(socket: Socket, buf: &[u8], remote: SocketAddr) -> Result<usize> {
+	unsafe { sys_udp_send_to(socket, buf, remote) }
+}
+
+pub fn udp_recv_from(socket: Socket, buf: &mut [u8]) -> Result<(usize, SocketAddr)> {
+	unsafe { sys_udp_recv_from(socket, buf) }
+}
+
+pub fn udp_set_broadcast(socket: Socket, broadcast: bool) -> Result<()> {
+	unsafe { sys_udp_set_broadcast(socket, broadcast) }
+}
+
+pub fn udp_broadcast(socket: Socket) -> Result<bool> {
+	unsafe { sys_udp_broadcast(socket) }
+}
+
+pub fn udp_set_multicast_loop(socket: Socket, loop_back: bool) -> Result<()> {
+	unsafe { sys_udp_set_multicast_loop(socket, loop_back) }
+}
+
+pub fn udp_multicast_loop(socket: Socket) -> Result<bool> {
+	unsafe { sys_udp_multicast_loop(socket) }
+}
+
+pub fn udp_join_multicast_v4(socket: Socket, multiaddr: Ipv4Addr, interface: Ipv4Addr) -> Result<()> {
+	unsafe { sys_udp_join_multicast_v4(socket, multiaddr, interface) }
+}
+
+pub fn udp_join_multicast_v6(socket: Socket, multiaddr: Ipv6Addr, interface: u32) -> Result<()> {
+	unsafe { sys_udp_join_multicast_v6(socket, multiaddr, interface) }
+}
@@ -0,0 +1,59 @@
+use super::waker::AsyncWakerSocket;
+use super::Socket as _;
+use hermit_abi::io;
+use hermit_abi::net::{self, SocketAddr};
+use std::future::poll_fn;
+use std::task::Poll;
+
+/// An async UDP socket, driven by the same [`AsyncWakerSocket`] readiness
+/// machinery TCP sockets use, so `send_to`/`recv_from` suspend the calling
+/// task instead of busy-polling on `WouldBlock`.
+#[derive(Debug)]
+pub(crate) struct UdpSocket {
+	handle: net::Socket,
+	waker: AsyncWakerSocket,
+}
+
+impl UdpSocket {
+	pub(crate) fn bind(local: SocketAddr) -> io::Result<Self> {
+		let handle = net::socket()?;
+		// Constructed before the fallible binds below so that `Drop` closes `handle`
+		// if either one fails, instead of leaking the descriptor on the error path.
+		let socket = Self {
+			handle,
+			waker: AsyncWakerSocket::new(Some(handle)),
+		};
+		net::udp_bind(socket.handle, local)?;
+		net::waker_bind(socket.handle)?;
+		Ok(socket)
+	}
+
+	pub(crate) async fn send_to(&mut self, buf: &[u8], remote: SocketAddr) -> io::Result<usize> {
+		poll_fn(|cx| match net::udp_send_to(self.handle, buf, remote) {
+			Err(e) if e.kind() == io::ErrorKind::WouldBlock => {
+				self.waker.register_exclusive_send_waker(cx.waker());
+				Poll::Pending
+			}
+			result => Poll::Ready(result),
+		})
+		.await
+	}
+
+	pub(crate) async fn recv_from(&mut self, buf: &mut [u8]) -> io::Result<(usize, SocketAddr)> {
+		poll_fn(|cx| match net::udp_recv_from(self.handle, buf) {
+			Err(e) if e.kind() == io::ErrorKind::WouldBlock => {
+				self.waker.register_exclusive_recv_waker(cx.waker());
+				Poll::Pending
+			}
+			result => Poll::Ready(result),
+		})
+		.await
+	}
+}
+
+impl Drop for UdpSocket {
+	fn drop(&mut self) {
+		self.waker.close();
+		let _ = net::socket_close(self.handle);
+	}
+}
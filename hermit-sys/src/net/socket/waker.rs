@@ -5,6 +5,12 @@ use hermit_abi::net::event::EventFlags;
 use std::task::Waker;
 use std::sync::atomic::{AtomicU32,Ordering};
 
+/// Shared readiness/waker state for an async socket.
+///
+/// `socket::udp::UdpSocket` drives this the same way TCP sockets do: a
+/// `WouldBlock` from the raw `hermit_abi::net::udp_*` binding registers a
+/// waker here instead of busy-polling, and `send_event` wakes it again once
+/// the executor observes new readiness for the handle.
 #[derive(Debug)]
 pub(crate) struct AsyncWakerSocket {
 	event_flags: AtomicU32,
@@ -1,4 +1,5 @@
 use core::fmt;
+use core::ops::{ControlFlow, FromResidual, Residual, Try};
 
 /// An ABI compatible [`Result`] type.
 ///
@@ -9,6 +10,18 @@ pub trait AbiResult: Copy + Sized + fmt::Display {
 	fn check(self) -> Result<OkVal<Self>, ErrVal<Self>>;
 }
 
+/// Linux `IS_ERR_VALUE` style [`AbiResult`] check for values that may carry a raw address or length.
+///
+/// Unlike [`AbiResult::check`], which treats every negative value as an error,
+/// [`check_bounded`](Self::check_bounded) only classifies values in the small band `[-4095, -1]`
+/// as errors. This matters for syscalls whose success value is a pointer or offset rather than a
+/// plain errno: an `isize` formed from an address near the top of the address space (e.g. an
+/// `mmap` result with the high bit set) is a huge negative number, but it is not an errno and
+/// must still be treated as [`Ok`].
+pub trait BoundedAbiResult: AbiResult {
+	fn check_bounded(self) -> Result<OkVal<Self>, ErrVal<Self>>;
+}
+
 /// Wrapper around a [`AbiResult`] value which is guaranteed to represent [`Ok`].
 #[repr(transparent)]
 #[derive(Debug, Clone, Copy)]
@@ -28,6 +41,26 @@ pub struct InvalidValueError<R: AbiResult> {
 /// Convertible to [`OkVal`].
 pub trait AsOkVal<R: AbiResult> {
 	fn as_ok(&self) -> OkVal<R>;
+
+	/// Convert to [`OkVal`] without checking that the value is representable.
+	///
+	/// Implementors for which every value of `Self` fits losslessly in `R` (e.g. signed
+	/// integers, pointers) may leave this at its default, which just calls [`AsOkVal::as_ok`].
+	/// Implementors that can overflow `R` (e.g. unsigned integers wider than `R`'s non-negative
+	/// range) should override this with a plain cast, for callers that have already proven the
+	/// value fits and want to skip the check.
+	fn as_ok_unchecked(&self) -> OkVal<R> {
+		self.as_ok()
+	}
+}
+
+/// Fallibly convertible to [`OkVal`].
+///
+/// Unlike [`AsOkVal::as_ok`], which panics when the value cannot be represented as `R`,
+/// this reports the failure so callers can propagate it instead of crashing on untrusted
+/// lengths/offsets.
+pub trait TryAsOkVal<R: AbiResult> {
+	fn try_as_ok(&self) -> Result<OkVal<R>, InvalidValueError<R>>;
 }
 
 /// Convertible to [`ErrVal`].
@@ -135,10 +168,18 @@ impl<R: AbiResult + fmt::Display> fmt::Display for InvalidValueError<R> {
 	}
 }
 
+impl<R: AbiResult + fmt::Display + fmt::Debug> core::error::Error for InvalidValueError<R> {}
+
 // -------------------------------------------------------
 // Implementations for ::core types
 // -------------------------------------------------------
 
+// `TryFrom<OkVal<R>>`/`TryFrom<ErrVal<R>>` can only be implemented per concrete `R`/`Self` pair
+// (not as a single blanket over `T: TryFromOkVal<R>`), since the orphan rules require a local
+// type among the impl's types before any uncovered generic parameter. Each `impl_*!` macro below
+// therefore emits its own concrete `TryFrom` arms alongside the existing `TryFromOkVal`/
+// `TryFromErrVal` ones.
+
 impl<R, T, E> TryFromAbiResult<R> for Result<T, E>
 where
 	R: AbiResult,
@@ -191,6 +232,51 @@ impl<R: AbiResult> TryFromErrVal<R> for R {
 	}
 }
 
+/// An [`AbiResult`] that participates in `?` via [`Try`]/[`FromResidual`].
+///
+/// Composing several syscall returns in one function otherwise means a manual
+/// `match value.check() { ... }` at every step. Wrapping the raw return in [`AbiOutcome`]
+/// lets that step become `value.try_branch()?`, short-circuiting to [`ErrVal`] the same way
+/// `Result` short-circuits to `Err`.
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy)]
+pub struct AbiOutcome<R: AbiResult>(R);
+
+impl<R: AbiResult> Try for AbiOutcome<R> {
+	type Output = OkVal<R>;
+	type Residual = ErrVal<R>;
+
+	fn from_output(output: Self::Output) -> Self {
+		Self(output.get())
+	}
+
+	fn branch(self) -> ControlFlow<Self::Residual, Self::Output> {
+		match self.0.check() {
+			Ok(ok) => ControlFlow::Continue(ok),
+			Err(err) => ControlFlow::Break(err),
+		}
+	}
+}
+
+impl<R: AbiResult> FromResidual<ErrVal<R>> for AbiOutcome<R> {
+	fn from_residual(residual: ErrVal<R>) -> Self {
+		Self(residual.get())
+	}
+}
+
+impl<R: AbiResult> Residual<OkVal<R>> for ErrVal<R> {
+	type TryType = AbiOutcome<R>;
+}
+
+/// Converts a raw [`AbiResult`] into an [`AbiOutcome`] that can be used with `?`.
+pub trait TryBranch: AbiResult {
+	fn try_branch(self) -> AbiOutcome<Self> {
+		AbiOutcome(self)
+	}
+}
+
+impl<R: AbiResult> TryBranch for R {}
+
 macro_rules! impl_signed {
 	( $( $signed:ty )* ) => {
 		$(
@@ -203,18 +289,69 @@ macro_rules! impl_signed {
 					}
 				}
 			}
+
+			impl TryFrom<OkVal<$signed>> for $signed {
+				type Error = InvalidValueError<$signed>;
+
+				fn try_from(ok: OkVal<$signed>) -> Result<Self, Self::Error> {
+					Self::try_from_ok(ok)
+				}
+			}
+
+			impl TryFrom<ErrVal<$signed>> for $signed {
+				type Error = InvalidValueError<$signed>;
+
+				fn try_from(err: ErrVal<$signed>) -> Result<Self, Self::Error> {
+					Self::try_from_err(err)
+				}
+			}
 		)*
 	};
 }
 
 impl_signed! { i8 i16 i32 i64 isize }
 
+macro_rules! impl_bounded {
+	( $( $signed:ty )* ) => {
+		$(
+			impl BoundedAbiResult for $signed {
+				fn check_bounded(self) -> Result<OkVal<Self>, ErrVal<Self>> {
+					const MAX_ERRNO: isize = 4095;
+					if (self as isize as usize) >= (-MAX_ERRNO as usize) {
+						Err(ErrVal(self))
+					} else {
+						Ok(OkVal(self))
+					}
+				}
+			}
+		)*
+	};
+}
+
+impl_bounded! { i8 i16 i32 i64 isize }
+
 macro_rules! impl_unsigned_ok {
 	( $( $unsigned:ty as $signed:ty )* ) => {
 		$(
 			impl AsOkVal<$signed> for $unsigned {
 				fn as_ok(&self) -> OkVal<$signed> {
-					(*self as $signed).as_ok()
+					self.try_as_ok()
+						.unwrap_or_else(|_| panic!("Value {self} is not a valid OkVal"))
+				}
+
+				fn as_ok_unchecked(&self) -> OkVal<$signed> {
+					unsafe { OkVal::new_unchecked(*self as $signed) }
+				}
+			}
+
+			impl TryAsOkVal<$signed> for $unsigned {
+				fn try_as_ok(&self) -> Result<OkVal<$signed>, InvalidValueError<$signed>> {
+					let signed = *self as $signed;
+					if signed.is_negative() {
+						Err(InvalidValueError { value: signed })
+					} else {
+						Ok(OkVal(signed))
+					}
 				}
 			}
 
@@ -223,6 +360,14 @@ macro_rules! impl_unsigned_ok {
 					Ok(ok.get() as $unsigned)
 				}
 			}
+
+			impl TryFrom<OkVal<$signed>> for $unsigned {
+				type Error = InvalidValueError<$signed>;
+
+				fn try_from(ok: OkVal<$signed>) -> Result<Self, Self::Error> {
+					Self::try_from_ok(ok)
+				}
+			}
 		)*
 	};
 }
@@ -251,6 +396,14 @@ macro_rules! impl_transmute_ok {
 					Ok(ty)
 				}
 			}
+
+			impl TryFrom<OkVal<$signed>> for $ty {
+				type Error = InvalidValueError<$signed>;
+
+				fn try_from(ok: OkVal<$signed>) -> Result<Self, Self::Error> {
+					Self::try_from_ok(ok)
+				}
+			}
 		)*
 	};
 }
@@ -282,6 +435,14 @@ macro_rules! impl_pointer_ok {
 					Ok(ok.get() as $pointer)
 				}
 			}
+
+			impl<$generics> TryFrom<OkVal<isize>> for $pointer {
+				type Error = InvalidValueError<isize>;
+
+				fn try_from(ok: OkVal<isize>) -> Result<Self, Self::Error> {
+					Self::try_from_ok(ok)
+				}
+			}
 		)*
 	};
 }
@@ -304,3 +465,11 @@ impl<T> TryFromOkVal<isize> for Option<core::ptr::NonNull<T>> {
 		Ok(ptr)
 	}
 }
+
+impl<T> TryFrom<OkVal<isize>> for Option<core::ptr::NonNull<T>> {
+	type Error = InvalidValueError<isize>;
+
+	fn try_from(ok: OkVal<isize>) -> Result<Self, Self::Error> {
+		Self::try_from_ok(ok)
+	}
+}
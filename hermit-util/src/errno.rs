@@ -0,0 +1,147 @@
+use crate::abi::{AsErrVal, ErrVal, InvalidValueError, TryFromErrVal};
+
+/// A POSIX errno, decoded from the magnitude of a negative [`AbiResult`](crate::abi::AbiResult).
+///
+/// Unrecognized codes are preserved via [`Errno::Other`] rather than being rejected, since the
+/// ABI surface grows syscalls (and therefore errno codes) independently of this enum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Errno {
+	EPERM,
+	ENOENT,
+	ESRCH,
+	EINTR,
+	EIO,
+	ENXIO,
+	E2BIG,
+	ENOEXEC,
+	EBADF,
+	ECHILD,
+	EAGAIN,
+	ENOMEM,
+	EACCES,
+	EFAULT,
+	ENOTBLK,
+	EBUSY,
+	EEXIST,
+	EXDEV,
+	ENODEV,
+	ENOTDIR,
+	EISDIR,
+	EINVAL,
+	ENFILE,
+	EMFILE,
+	ENOTTY,
+	ETXTBSY,
+	EFBIG,
+	ENOSPC,
+	ESPIPE,
+	EROFS,
+	EMLINK,
+	EPIPE,
+	EDOM,
+	ERANGE,
+	/// An errno code not covered by a dedicated variant.
+	Other(u16),
+}
+
+impl Errno {
+	fn code(self) -> u16 {
+		match self {
+			Self::EPERM => 1,
+			Self::ENOENT => 2,
+			Self::ESRCH => 3,
+			Self::EINTR => 4,
+			Self::EIO => 5,
+			Self::ENXIO => 6,
+			Self::E2BIG => 7,
+			Self::ENOEXEC => 8,
+			Self::EBADF => 9,
+			Self::ECHILD => 10,
+			Self::EAGAIN => 11,
+			Self::ENOMEM => 12,
+			Self::EACCES => 13,
+			Self::EFAULT => 14,
+			Self::ENOTBLK => 15,
+			Self::EBUSY => 16,
+			Self::EEXIST => 17,
+			Self::EXDEV => 18,
+			Self::ENODEV => 19,
+			Self::ENOTDIR => 20,
+			Self::EISDIR => 21,
+			Self::EINVAL => 22,
+			Self::ENFILE => 23,
+			Self::EMFILE => 24,
+			Self::ENOTTY => 25,
+			Self::ETXTBSY => 26,
+			Self::EFBIG => 27,
+			Self::ENOSPC => 28,
+			Self::ESPIPE => 29,
+			Self::EROFS => 30,
+			Self::EMLINK => 31,
+			Self::EPIPE => 32,
+			Self::EDOM => 33,
+			Self::ERANGE => 34,
+			Self::Other(code) => code,
+		}
+	}
+
+	fn from_code(code: u16) -> Self {
+		match code {
+			1 => Self::EPERM,
+			2 => Self::ENOENT,
+			3 => Self::ESRCH,
+			4 => Self::EINTR,
+			5 => Self::EIO,
+			6 => Self::ENXIO,
+			7 => Self::E2BIG,
+			8 => Self::ENOEXEC,
+			9 => Self::EBADF,
+			10 => Self::ECHILD,
+			11 => Self::EAGAIN,
+			12 => Self::ENOMEM,
+			13 => Self::EACCES,
+			14 => Self::EFAULT,
+			15 => Self::ENOTBLK,
+			16 => Self::EBUSY,
+			17 => Self::EEXIST,
+			18 => Self::EXDEV,
+			19 => Self::ENODEV,
+			20 => Self::ENOTDIR,
+			21 => Self::EISDIR,
+			22 => Self::EINVAL,
+			23 => Self::ENFILE,
+			24 => Self::EMFILE,
+			25 => Self::ENOTTY,
+			26 => Self::ETXTBSY,
+			27 => Self::EFBIG,
+			28 => Self::ENOSPC,
+			29 => Self::ESPIPE,
+			30 => Self::EROFS,
+			31 => Self::EMLINK,
+			32 => Self::EPIPE,
+			33 => Self::EDOM,
+			34 => Self::ERANGE,
+			other => Self::Other(other),
+		}
+	}
+}
+
+impl TryFromErrVal<isize> for Errno {
+	fn try_from_err(err: ErrVal<isize>) -> Result<Self, InvalidValueError<isize>> {
+		Ok(Self::from_code((-err.get()) as u16))
+	}
+}
+
+impl AsErrVal<isize> for Errno {
+	fn as_err(&self) -> ErrVal<isize> {
+		ErrVal::new(-(self.code() as isize)).expect("errno code does not fit the ABI result type")
+	}
+}
+
+impl TryFrom<ErrVal<isize>> for Errno {
+	type Error = InvalidValueError<isize>;
+
+	fn try_from(err: ErrVal<isize>) -> Result<Self, Self::Error> {
+		Self::try_from_err(err)
+	}
+}
@@ -1,7 +1,11 @@
 #![no_std]
+#![feature(try_trait_v2)]
+#![feature(try_trait_v2_residual)]
 
 /// ABI related utilities
 pub mod abi;
+/// POSIX errno decoding for [`abi`] results
+pub mod errno;
 /// Derive macros
 pub mod derive {
 	/// Derives the [`TryFromErr`](crate::abi::TryFromErrVal) and [`AsErr`](crate::abi::AsErrVal)
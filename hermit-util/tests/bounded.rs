@@ -0,0 +1,19 @@
+use hermit_util::abi::BoundedAbiResult;
+
+#[test]
+fn small_negative_values_are_errors() {
+	assert!((-1isize).check_bounded().is_err());
+	assert!((-4095isize).check_bounded().is_err());
+}
+
+#[test]
+fn values_outside_the_errno_band_are_ok() {
+	assert!((-4096isize).check_bounded().is_ok());
+	assert!(isize::MIN.check_bounded().is_ok());
+}
+
+#[test]
+fn non_negative_values_are_ok() {
+	assert!(0isize.check_bounded().is_ok());
+	assert!(isize::MAX.check_bounded().is_ok());
+}
@@ -0,0 +1,23 @@
+use hermit_util::abi::{AsErrVal, ErrVal, TryFromErrVal};
+use hermit_util::errno::Errno;
+
+#[test]
+fn known_code_round_trips() {
+	let err = ErrVal::new(-22isize).unwrap();
+	assert_eq!(Errno::try_from_err(err).unwrap(), Errno::EINVAL);
+	assert_eq!(Errno::EINVAL.as_err().get(), -22);
+}
+
+#[test]
+fn unknown_code_falls_back_to_other() {
+	let err = ErrVal::new(-200isize).unwrap();
+	assert_eq!(Errno::try_from_err(err).unwrap(), Errno::Other(200));
+	assert_eq!(Errno::Other(200).as_err().get(), -200);
+}
+
+#[test]
+fn try_into_from_err_val() {
+	let err = ErrVal::new(-22isize).unwrap();
+	let errno: Errno = err.try_into().unwrap();
+	assert_eq!(errno, Errno::EINVAL);
+}
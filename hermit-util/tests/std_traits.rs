@@ -0,0 +1,29 @@
+use hermit_util::abi::{ErrVal, OkVal};
+
+#[test]
+fn try_into_from_ok_val() {
+	let ok = OkVal::new(7isize).unwrap();
+	let n: isize = ok.try_into().unwrap();
+	assert_eq!(n, 7);
+}
+
+#[test]
+fn try_into_from_err_val() {
+	let err = ErrVal::new(-7isize).unwrap();
+	let n: isize = err.try_into().unwrap();
+	assert_eq!(n, -7);
+}
+
+#[test]
+fn invalid_value_error_is_a_core_error() {
+	fn assert_error<E: core::error::Error>() {}
+	assert_error::<hermit_util::abi::InvalidValueError<isize>>();
+}
+
+#[test]
+fn try_into_pointer_from_ok_val() {
+	let addr = 0x1000isize;
+	let ok = OkVal::new(addr).unwrap();
+	let ptr: *const u8 = ok.try_into().unwrap();
+	assert_eq!(ptr as isize, addr);
+}
@@ -0,0 +1,25 @@
+#![feature(try_trait_v2)]
+
+use core::ops::Try;
+use hermit_util::abi::{AbiOutcome, ErrVal, OkVal, TryBranch};
+
+fn sum_two_syscalls(a: isize, b: isize) -> AbiOutcome<isize> {
+	let a = a.try_branch()?;
+	let b = b.try_branch()?;
+	AbiOutcome::from_output(unsafe { OkVal::new_unchecked(a.get() + b.get()) })
+}
+
+#[test]
+fn short_circuits_on_first_error() {
+	use core::ops::ControlFlow;
+	assert!(matches!(sum_two_syscalls(3, 4).branch(), ControlFlow::Continue(ok) if ok.get() == 7));
+	assert!(matches!(sum_two_syscalls(-1, 4).branch(), ControlFlow::Break(err) if err.get() == -1));
+	assert!(matches!(sum_two_syscalls(3, -2).branch(), ControlFlow::Break(err) if err.get() == -2));
+}
+
+#[test]
+fn err_val_residual_feeds_back_into_outcome() {
+	use core::ops::{ControlFlow, FromResidual};
+	let outcome: AbiOutcome<isize> = AbiOutcome::from_residual(ErrVal::new(-5).unwrap());
+	assert!(matches!(outcome.branch(), ControlFlow::Break(err) if err.get() == -5));
+}
@@ -0,0 +1,13 @@
+use hermit_util::abi::TryAsOkVal;
+
+#[test]
+fn in_range_value_converts() {
+	let ok = (i64::MAX as u64).try_as_ok().unwrap();
+	assert_eq!(ok.get(), i64::MAX);
+}
+
+#[test]
+fn value_above_signed_max_is_rejected() {
+	assert!(((i64::MAX as u64) + 1).try_as_ok().is_err());
+	assert!(u64::MAX.try_as_ok().is_err());
+}